@@ -1,60 +1,191 @@
 use anyhow::Result;
+use ropey::Rope;
 use std::cmp::{max, min};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 use termwiz::{
     caps::Capabilities,
+    cell::AttributeChange,
     color::*,
     input::*,
     surface::*,
     terminal::{buffered::BufferedTerminal, SystemTerminal, Terminal},
 };
+use unicode_width::UnicodeWidthChar;
+
+/// Number of consecutive Ctrl-Q presses required to quit with unsaved changes.
+const QUIT_TIMES: u8 = 3;
+
+/// How long a status message stays visible before it's cleared.
+const STATUS_MESSAGE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Width a tab character is expanded to (rounding up to the next multiple).
+const TAB_STOP: usize = 4;
+
+/// Number of bytes shown per row in hex mode.
+const BYTES_PER_LINE: usize = 16;
+
+/// How long `run`'s event loop waits for input before redrawing anyway, so
+/// resizes and other async events are picked up without a keypress.
+const INPUT_POLL_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Finds the first occurrence of `needle` in `hay` at or after index 0, in char units.
+fn find_subslice(hay: &[char], needle: &[char]) -> Option<usize> {
+    if needle.is_empty() || needle.len() > hay.len() {
+        return None;
+    }
+    (0..=hay.len() - needle.len()).find(|&i| &hay[i..i + needle.len()] == needle)
+}
+
+/// Finds the last occurrence of `needle` in `hay`, in char units.
+fn rfind_subslice(hay: &[char], needle: &[char]) -> Option<usize> {
+    if needle.is_empty() || needle.len() > hay.len() {
+        return None;
+    }
+    (0..=hay.len() - needle.len())
+        .rev()
+        .find(|&i| &hay[i..i + needle.len()] == needle)
+}
+
+/// An edit recorded for undo/redo, inverses of each other by construction:
+/// undoing an `Insert` deletes `text` back out, undoing a `Delete` re-inserts it.
+#[derive(Clone)]
+enum EditOp {
+    Insert { cy: usize, cx: usize, text: String },
+    Delete { cy: usize, cx: usize, text: String },
+}
+
+/// Which view the editor renders and which keys act on.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Text,
+    Hex,
+}
+
+/// A message forwarded from the background input thread to `run`'s event loop.
+enum TermEvent {
+    Input(InputEvent),
+    Error(String),
+}
 
 pub struct Editor {
     bt: BufferedTerminal<SystemTerminal>,
     should_quit: bool,
-    buffer: Buffer,
+    /// Every open file, each with its own cursor, scroll offsets and dirty flag.
+    buffers: Vec<Buffer>,
+    /// Index into `buffers` of the buffer currently shown and edited.
+    active: usize,
+    quit_times: u8,
+    status_message: Option<(String, Instant)>,
+    /// Current incremental-search match: (line, starting column, length in chars).
+    search_match: Option<(usize, usize, usize)>,
 }
 
 pub struct Buffer {
+    path: Option<PathBuf>,
     roff: usize,
     coff: usize,
     cx: usize,
     cy: usize,
     w: usize,
     h: usize,
-    lines: Vec<Vec<char>>,
+    rope: Rope,
+    dirty: bool,
+    undo_stack: Vec<Vec<EditOp>>,
+    redo_stack: Vec<Vec<EditOp>>,
+    pending: Vec<EditOp>,
+    /// Which view this buffer renders and which keys act on.
+    mode: Mode,
+    /// Flat byte view used by hex mode; built from `rope` on entering it (or
+    /// read straight from disk for a non-UTF-8 file), written to disk as-is
+    /// when saving in hex mode, and turned back into `rope` on leaving hex
+    /// mode only when it is valid UTF-8.
+    hex_bytes: Vec<u8>,
+    hex_cursor: usize,
+    hex_high_nibble: bool,
+    hex_roff: usize,
 }
 
 impl Default for Buffer {
     fn default() -> Self {
         Self {
+            path: None,
             roff: 0,
             coff: 0,
             cx: 0,
             cy: 0,
             w: 0,
             h: 0,
-            lines: vec![vec![]],
+            rope: Rope::new(),
+            dirty: false,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            pending: Vec::new(),
+            mode: Mode::Text,
+            hex_bytes: Vec::new(),
+            hex_cursor: 0,
+            hex_high_nibble: true,
+            hex_roff: 0,
         }
     }
 }
 
 impl Buffer {
-    fn line(&mut self) -> &mut Vec<char> {
-        self.lines.get_mut(self.cy).unwrap()
+    /// Number of editable lines. Ropey counts a phantom trailing empty line
+    /// whenever the text ends in a newline (`"foo\n".len_lines() == 2`); that
+    /// line isn't real content, so it's excluded here to match the line count
+    /// a user would expect from the file on disk.
+    fn num_lines(&self) -> usize {
+        let n = self.rope.len_lines();
+        if n > 1 && self.rope.line(n - 1).len_chars() == 0 {
+            n - 1
+        } else {
+            n
+        }
+    }
+
+    /// Length of line `cy` in chars, excluding its trailing newline (if any).
+    fn line_len(&self, cy: usize) -> usize {
+        let len = self.rope.line(cy).len_chars();
+        if cy + 1 < self.rope.len_lines() {
+            len - 1
+        } else {
+            len
+        }
+    }
+
+    /// Converts a (line, column) pair to an absolute char index into the rope.
+    fn char_idx(&self, cy: usize, cx: usize) -> usize {
+        self.rope.line_to_char(cy) + cx
+    }
+
+    fn char_at(&self, cy: usize, cx: usize) -> char {
+        self.rope.char(self.char_idx(cy, cx))
+    }
+
+    /// Chars of line `cy`, excluding its trailing newline.
+    fn line_chars(&self, cy: usize) -> Vec<char> {
+        self.rope.line(cy).chars().take(self.line_len(cy)).collect()
     }
 
     pub fn push(&mut self, c: char) {
         let cx = self.cx;
+        let cy = self.cy;
+
+        self.dirty = true;
+        self.record(EditOp::Insert {
+            cy,
+            cx,
+            text: c.to_string(),
+        });
+
+        self.rope.insert_char(self.char_idx(cy, cx), c);
 
         if c == '\n' {
-            let new_line = self.line().drain(cx..).collect();
-            self.lines.insert(self.cy + 1, new_line);
-            self.move_caret(1, -(self.cy as i32));
+            self.move_caret(1, -(cy as i32));
         } else {
-            self.line().insert(cx, c);
             self.move_caret(0, 1);
         }
     }
@@ -62,44 +193,272 @@ impl Buffer {
     pub fn backspace(&mut self) {
         let (cx, cy) = (self.cx, self.cy);
 
+        if cx == 0 && cy == 0 {
+            return;
+        }
+        self.dirty = true;
+
         if cx == 0 && cy != 0 {
-            let line = self.lines.remove(cy);
+            let prev_len = self.line_len(cy - 1);
+            self.record(EditOp::Delete {
+                cy: cy - 1,
+                cx: prev_len,
+                text: "\n".to_string(),
+            });
+            let idx = self.char_idx(cy - 1, prev_len);
+            self.rope.remove(idx..idx + 1);
             self.move_caret(-1, 0);
-            let len = self.line().len() as i32 - cx as i32;
+            let len = prev_len as i32 - cx as i32;
             self.move_caret(0, len);
-            self.line().extend(line.iter());
         } else if cx != 0 {
-            self.line().remove(cx - 1);
+            let removed = self.char_at(cy, cx - 1);
+            self.record(EditOp::Delete {
+                cy,
+                cx: cx - 1,
+                text: removed.to_string(),
+            });
+            let idx = self.char_idx(cy, cx - 1);
+            self.rope.remove(idx..idx + 1);
             self.move_caret(0, -1);
         }
     }
 
     pub fn delete(&mut self) {
         let (cx, cy) = (self.cx, self.cy);
+        let len = self.line_len(cy);
+
+        if cx == len && cy == self.num_lines() - 1 {
+            return;
+        }
+        self.dirty = true;
+
+        if cx == len && cy != self.num_lines() - 1 {
+            self.record(EditOp::Delete {
+                cy,
+                cx,
+                text: "\n".to_string(),
+            });
+            let idx = self.char_idx(cy, cx);
+            self.rope.remove(idx..idx + 1);
+        } else if cx != len {
+            let removed = self.char_at(cy, cx);
+            self.record(EditOp::Delete {
+                cy,
+                cx,
+                text: removed.to_string(),
+            });
+            let idx = self.char_idx(cy, cx);
+            self.rope.remove(idx..idx + 1);
+        }
+    }
+
+    /// Records `op` for undo, coalescing it into the in-progress group of
+    /// contiguous single-character inserts when possible.
+    fn record(&mut self, op: EditOp) {
+        self.redo_stack.clear();
+
+        let coalesced = if let (
+            Some(EditOp::Insert {
+                cy: lcy,
+                cx: lcx,
+                text: ltext,
+            }),
+            EditOp::Insert { cy, cx, text },
+        ) = (self.pending.last_mut(), &op)
+        {
+            *lcy == *cy
+                && *lcx + ltext.chars().count() == *cx
+                && text.as_str() != "\n"
+                && ltext.as_str() != "\n"
+                && {
+                    ltext.push_str(text);
+                    true
+                }
+        } else {
+            false
+        };
 
-        if cx == self.line().len() && self.cy != self.lines.len() - 1 {
-            let line = self.lines.remove(cy + 1);
-            self.line().extend(line.iter());
-        } else if cx != self.line().len() {
-            self.line().remove(cx);
+        if !coalesced {
+            self.flush_group();
+            self.pending.push(op);
         }
     }
 
+    /// Closes the in-progress edit group so a later undo stops here.
+    fn flush_group(&mut self) {
+        if !self.pending.is_empty() {
+            self.undo_stack.push(std::mem::take(&mut self.pending));
+        }
+    }
+
+    fn apply_insert(&mut self, cy: usize, cx: usize, text: &str) {
+        self.rope.insert(self.char_idx(cy, cx), text);
+        self.cy = cy;
+        self.cx = cx;
+        if text == "\n" {
+            self.move_caret(1, -(cy as i32));
+        } else {
+            self.move_caret(0, text.chars().count() as i32);
+        }
+    }
+
+    fn apply_delete(&mut self, cy: usize, cx: usize, text: &str) {
+        let idx = self.char_idx(cy, cx);
+        self.rope.remove(idx..idx + text.chars().count());
+        self.cy = cy;
+        self.cx = cx;
+        self.move_caret(0, 0);
+    }
+
+    fn apply(&mut self, op: &EditOp) {
+        match op {
+            EditOp::Insert { cy, cx, text } => self.apply_insert(*cy, *cx, text),
+            EditOp::Delete { cy, cx, text } => self.apply_delete(*cy, *cx, text),
+        }
+    }
+
+    fn invert(&mut self, op: &EditOp) {
+        match op {
+            EditOp::Insert { cy, cx, text } => self.apply_delete(*cy, *cx, text),
+            EditOp::Delete { cy, cx, text } => self.apply_insert(*cy, *cx, text),
+        }
+    }
+
+    /// Reverses the most recent edit group. The cursor lands where undone
+    /// inserted text began, or just past re-inserted deleted text; after
+    /// undoing a forward delete (Delete key) it is therefore one character,
+    /// or one line break, past where it stood before that delete.
+    pub fn undo(&mut self) {
+        self.flush_group();
+        let Some(group) = self.undo_stack.pop() else {
+            return;
+        };
+        for op in group.iter().rev() {
+            self.invert(op);
+        }
+        self.redo_stack.push(group);
+        self.dirty = true;
+    }
+
+    /// Re-applies the most recently undone edit group.
+    pub fn redo(&mut self) {
+        let Some(group) = self.redo_stack.pop() else {
+            return;
+        };
+        for op in group.iter() {
+            self.apply(op);
+        }
+        self.undo_stack.push(group);
+        self.dirty = true;
+    }
+
+    /// Serializes the buffer's contents back to a plain string.
+    pub fn contents(&self) -> String {
+        self.rope.to_string()
+    }
+
+    /// Expands tabs to the next `TAB_STOP` and returns the on-screen
+    /// rendering of line `idx`, used for display and horizontal scrolling.
+    fn render_line(&self, idx: usize) -> String {
+        let mut rendered = String::new();
+        let mut col = 0usize;
+        for c in self.rope.line(idx).chars().take(self.line_len(idx)) {
+            if c == '\t' {
+                let spaces = TAB_STOP - (col % TAB_STOP);
+                rendered.push_str(&" ".repeat(spaces));
+                col += spaces;
+            } else {
+                rendered.push(c);
+                col += c.width().unwrap_or(0);
+            }
+        }
+        rendered
+    }
+
+    /// Converts character index `cx` on line `cy` to its on-screen column,
+    /// expanding tabs and accounting for double-width glyphs.
+    fn render_x(&self, cy: usize, cx: usize) -> usize {
+        let mut col = 0usize;
+        for c in self.rope.line(cy).chars().take(cx) {
+            if c == '\t' {
+                col += TAB_STOP - (col % TAB_STOP);
+            } else {
+                col += c.width().unwrap_or(0);
+            }
+        }
+        col
+    }
+
     pub fn move_caret(&mut self, row: i32, col: i32) {
-        let num_lines = self.lines.len() as i32;
+        let num_lines = self.num_lines() as i32;
         self.cy = min(max(self.cy as i32 + row, 0), num_lines - 1) as usize;
+        let view_height = self.h.saturating_sub(3);
         if self.cy < self.roff {
             self.roff = self.cy;
-        } else if self.cy > self.roff + (self.h as usize - 2) {
-            self.roff = self.cy - (self.h as usize - 2);
+        } else if self.cy > self.roff + view_height {
+            self.roff = self.cy - view_height;
         }
 
-        let line_len = self.line().len() as i32;
+        let line_len = self.line_len(self.cy) as i32;
         self.cx = min(max(self.cx as i32 + col, 0), line_len) as usize;
-        if self.cx < self.coff {
-            self.coff = self.cx;
-        } else if self.cx > self.coff + (self.w as usize - 1) {
-            self.coff = self.cx - (self.w as usize - 1);
+
+        let rx = self.render_x(self.cy, self.cx);
+        let view_width = self.w.saturating_sub(1);
+        if rx < self.coff {
+            self.coff = rx;
+        } else if rx > self.coff + view_width {
+            self.coff = rx - view_width;
+        }
+    }
+
+    /// Moves the hex-mode cursor by `delta` bytes, clamping to the buffer and
+    /// scrolling so its row stays within the viewport.
+    fn move_hex_caret(&mut self, delta: i32) {
+        let len = self.hex_bytes.len() as i32;
+        self.hex_cursor = min(max(self.hex_cursor as i32 + delta, 0), len) as usize;
+        self.hex_high_nibble = true;
+
+        let view_height = self.h.saturating_sub(2);
+        let row = self.hex_cursor / BYTES_PER_LINE;
+        if row < self.hex_roff {
+            self.hex_roff = row;
+        } else if view_height > 0 && row > self.hex_roff + view_height - 1 {
+            self.hex_roff = row - (view_height - 1);
+        }
+    }
+
+    /// Overwrites the current nibble of the byte at the hex cursor with
+    /// `digit`, then advances: high nibble to low nibble, low nibble to the
+    /// next byte.
+    fn hex_write_nibble(&mut self, digit: u8) {
+        if self.hex_cursor == self.hex_bytes.len() {
+            self.hex_bytes.push(0);
+        }
+        let byte = &mut self.hex_bytes[self.hex_cursor];
+        if self.hex_high_nibble {
+            *byte = (*byte & 0x0f) | (digit << 4);
+            self.hex_high_nibble = false;
+        } else {
+            *byte = (*byte & 0xf0) | digit;
+            self.hex_high_nibble = true;
+            self.move_hex_caret(1);
+        }
+        self.dirty = true;
+    }
+
+    /// Inserts a zeroed byte at the hex cursor, ready to be overwritten.
+    fn hex_insert(&mut self) {
+        self.hex_bytes.insert(self.hex_cursor, 0);
+        self.hex_high_nibble = true;
+        self.dirty = true;
+    }
+
+    /// Deletes the byte at the hex cursor, if any.
+    fn hex_delete(&mut self) {
+        if self.hex_cursor < self.hex_bytes.len() {
+            self.hex_bytes.remove(self.hex_cursor);
+            self.hex_high_nibble = true;
+            self.dirty = true;
         }
     }
 }
@@ -116,29 +475,315 @@ impl Editor {
         Ok(Self {
             bt: buf,
             should_quit: false,
-            buffer,
+            buffers: vec![buffer],
+            active: 0,
+            quit_times: QUIT_TIMES,
+            status_message: None,
+            search_match: None,
         })
     }
 
+    /// Shows `msg` on the message row until it times out or is replaced.
+    fn set_status_message(&mut self, msg: impl Into<String>) {
+        self.status_message = Some((msg.into(), Instant::now()));
+    }
+
+    /// Switches between the text and hex views, converting the buffer's
+    /// in-memory representation to match. Hex stays the byte buffer of
+    /// record: switching to text only rebuilds the rope from it when it's
+    /// valid UTF-8, so a stray non-UTF-8 byte typed in hex mode can never be
+    /// silently replaced with U+FFFD and lost.
+    fn toggle_mode(&mut self) {
+        match self.buffers[self.active].mode {
+            Mode::Text => {
+                let contents = self.buffers[self.active].contents().into_bytes();
+                self.buffers[self.active].hex_bytes = contents;
+                self.buffers[self.active].hex_cursor = 0;
+                self.buffers[self.active].hex_high_nibble = true;
+                self.buffers[self.active].hex_roff = 0;
+                self.buffers[self.active].mode = Mode::Hex;
+            }
+            Mode::Hex => {
+                let text = match String::from_utf8(self.buffers[self.active].hex_bytes.clone()) {
+                    Ok(text) => text,
+                    Err(_) => {
+                        self.set_status_message("Can't switch to text: not valid UTF-8");
+                        return;
+                    }
+                };
+                self.buffers[self.active].rope = Rope::from_str(&text);
+                self.buffers[self.active].cy = 0;
+                self.buffers[self.active].cx = 0;
+                self.buffers[self.active].move_caret(0, 0);
+                self.buffers[self.active].mode = Mode::Text;
+            }
+        }
+    }
+
+    /// Loads `path` into a new buffer and makes it active, reusing the
+    /// initial untitled buffer for the first file opened. Reads raw bytes
+    /// rather than decoding through `Rope::from_reader` so a non-UTF-8 file
+    /// opens straight into hex mode instead of failing to load at all.
     pub fn open(&mut self, path: PathBuf) -> Result<()> {
-        let file = File::open(path)?;
-        self.buffer.lines = BufReader::new(file)
-            .lines()
-            .map(|l| l.unwrap().chars().collect())
-            .collect();
+        let data = std::fs::read(&path)?;
+
+        let (w, h) = self.bt.dimensions();
+        let mut buffer = Buffer {
+            w,
+            h,
+            path: Some(path),
+            ..Default::default()
+        };
+
+        match String::from_utf8(data) {
+            Ok(text) => buffer.rope = Rope::from_str(&text),
+            Err(e) => {
+                buffer.hex_bytes = e.into_bytes();
+                buffer.mode = Mode::Hex;
+            }
+        }
+
+        if self.buffers.len() == 1 && self.buffers[0].path.is_none() {
+            self.buffers[0] = buffer;
+        } else {
+            self.buffers.push(buffer);
+        }
+        self.active = self.buffers.len() - 1;
+        Ok(())
+    }
+
+    /// Switches to the next open buffer, wrapping around.
+    fn next_buffer(&mut self) {
+        self.active = (self.active + 1) % self.buffers.len();
+    }
+
+    /// Switches to the previous open buffer, wrapping around.
+    fn prev_buffer(&mut self) {
+        self.active = (self.active + self.buffers.len() - 1) % self.buffers.len();
+    }
+
+    /// Opens a picker (Ctrl-B) on the message row listing open buffers by
+    /// filename, filtered as the user types; Enter switches to the highlighted entry, Escape cancels.
+    fn pick_buffer(&mut self, rx: &mpsc::Receiver<TermEvent>) -> Result<()> {
+        let mut query = String::new();
+        let mut selected = 0usize;
+
+        loop {
+            let names: Vec<String> = self
+                .buffers
+                .iter()
+                .map(|b| {
+                    b.path
+                        .as_ref()
+                        .and_then(|p| p.file_name())
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| "[No Name]".to_string())
+                })
+                .collect();
+            let matches: Vec<usize> = (0..names.len())
+                .filter(|&i| names[i].to_lowercase().contains(&query.to_lowercase()))
+                .collect();
+            if matches.is_empty() {
+                selected = 0;
+            } else {
+                selected = selected.min(matches.len() - 1);
+            }
+
+            self.draw_screen();
+            let listing = matches
+                .iter()
+                .enumerate()
+                .map(|(i, &idx)| {
+                    if i == selected {
+                        format!("[{}]", names[idx])
+                    } else {
+                        names[idx].clone()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+            self.set_status_message(format!("Switch to: {}  {}", query, listing));
+            self.draw_message_bar();
+            self.bt.flush()?;
+
+            match self.recv_input(rx)? {
+                InputEvent::Resized { cols, rows } => self.handle_resize(cols, rows),
+                InputEvent::Key(KeyEvent {
+                    key: KeyCode::Escape,
+                    ..
+                }) => return Ok(()),
+                InputEvent::Key(KeyEvent {
+                    key: KeyCode::Enter,
+                    ..
+                }) => {
+                    if let Some(&idx) = matches.get(selected) {
+                        self.active = idx;
+                    }
+                    return Ok(());
+                }
+                InputEvent::Key(KeyEvent {
+                    key: KeyCode::DownArrow | KeyCode::RightArrow,
+                    ..
+                }) => {
+                    if !matches.is_empty() {
+                        selected = (selected + 1) % matches.len();
+                    }
+                }
+                InputEvent::Key(KeyEvent {
+                    key: KeyCode::UpArrow | KeyCode::LeftArrow,
+                    ..
+                }) => {
+                    if !matches.is_empty() {
+                        selected = (selected + matches.len() - 1) % matches.len();
+                    }
+                }
+                InputEvent::Key(KeyEvent {
+                    key: KeyCode::Backspace,
+                    ..
+                }) => {
+                    query.pop();
+                    selected = 0;
+                }
+                InputEvent::Key(KeyEvent {
+                    key: KeyCode::Char(c),
+                    ..
+                }) => {
+                    query.push(c);
+                    selected = 0;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Writes the active buffer to its path, prompting for one if unset.
+    fn save(&mut self, rx: &mpsc::Receiver<TermEvent>) -> Result<()> {
+        if self.buffers[self.active].path.is_none() {
+            match self.prompt("Save as: ", rx)? {
+                Some(input) if !input.is_empty() => {
+                    self.buffers[self.active].path = Some(PathBuf::from(input));
+                }
+                _ => {
+                    self.set_status_message("Save aborted");
+                    return Ok(());
+                }
+            }
+        }
+
+        let path = self.buffers[self.active].path.as_ref().unwrap();
+        let data = match self.buffers[self.active].mode {
+            Mode::Text => self.buffers[self.active].contents().into_bytes(),
+            Mode::Hex => self.buffers[self.active].hex_bytes.clone(),
+        };
+        match std::fs::write(path, &data) {
+            Ok(()) => {
+                self.buffers[self.active].dirty = false;
+                self.set_status_message(format!("Saved {} bytes", data.len()));
+            }
+            Err(e) => {
+                self.set_status_message(format!("Can't save: {}", e));
+            }
+        }
         Ok(())
     }
 
+    /// Reads a line of input from the user, echoed on the last terminal row.
+    fn prompt(&mut self, prompt: &str, rx: &mpsc::Receiver<TermEvent>) -> Result<Option<String>> {
+        let mut input = String::new();
+        loop {
+            self.bt.add_changes(vec![
+                Change::CursorPosition {
+                    x: Position::Absolute(0),
+                    y: Position::Absolute(self.buffers[self.active].h - 1),
+                },
+                Change::ClearToEndOfLine(ColorAttribute::Default),
+            ]);
+            self.bt.add_change(format!("{}{}", prompt, input));
+            self.bt.flush()?;
+
+            match self.recv_input(rx)? {
+                InputEvent::Resized { cols, rows } => self.handle_resize(cols, rows),
+                InputEvent::Key(KeyEvent {
+                    key: KeyCode::Enter,
+                    ..
+                }) => return Ok(Some(input)),
+                InputEvent::Key(KeyEvent {
+                    key: KeyCode::Escape,
+                    ..
+                }) => return Ok(None),
+                InputEvent::Key(KeyEvent {
+                    key: KeyCode::Backspace,
+                    ..
+                }) => {
+                    input.pop();
+                }
+                InputEvent::Key(KeyEvent {
+                    key: KeyCode::Char(c),
+                    ..
+                }) => {
+                    input.push(c);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Spawns a thread that blocks on its own terminal handle's `poll_input`
+    /// and forwards events (including resizes) over a channel, so `run` can
+    /// redraw on a timeout instead of blocking on a single `poll_input` call.
+    fn spawn_input_thread(&self) -> Result<mpsc::Receiver<TermEvent>> {
+        let mut input_terminal = SystemTerminal::new(Capabilities::new_from_env()?)?;
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || loop {
+            match input_terminal.poll_input(None) {
+                Ok(Some(event)) => {
+                    if tx.send(TermEvent::Input(event)).is_err() {
+                        break;
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    let _ = tx.send(TermEvent::Error(format!("{:?}", e)));
+                    break;
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+
+    /// Blocks for the next input event on `rx`, propagating a terminal error
+    /// as an `Err` instead of a status message — used by modal loops (search,
+    /// prompt, buffer picker) that must not race the background input thread
+    /// by polling the terminal directly.
+    fn recv_input(&self, rx: &mpsc::Receiver<TermEvent>) -> Result<InputEvent> {
+        match rx.recv()? {
+            TermEvent::Input(event) => Ok(event),
+            TermEvent::Error(e) => anyhow::bail!(e),
+        }
+    }
+
     pub fn run(&mut self) -> Result<()> {
         self.bt.terminal().enter_alternate_screen()?;
         self.bt.terminal().set_raw_mode()?;
         self.bt.flush()?;
 
+        let rx = self.spawn_input_thread()?;
+
         loop {
             self.draw_screen();
             self.bt.flush()?;
 
-            self.handle_keys()?;
+            match rx.recv_timeout(INPUT_POLL_TIMEOUT) {
+                Ok(TermEvent::Input(input)) => self.handle_input(input, &rx)?,
+                Ok(TermEvent::Error(e)) => {
+                    self.set_status_message(e);
+                    self.should_quit = true;
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Err(mpsc::RecvTimeoutError::Disconnected) => self.should_quit = true,
+            }
             if self.should_quit {
                 break;
             }
@@ -159,106 +804,577 @@ impl Editor {
             },
         ]);
 
-        for i in self.buffer.roff..(self.buffer.roff + self.buffer.h - 1) {
-            if i < self.buffer.lines.len() {
-                let line = self.buffer.lines.get(i).unwrap();
-                if line.len() < self.buffer.coff {
-                    self.bt.add_change("\r\n");
-                    continue;
-                }
+        let (cx, cy) = match self.buffers[self.active].mode {
+            Mode::Text => self.draw_text(),
+            Mode::Hex => self.draw_hex(),
+        };
 
-                let part =
-                    &line[self.buffer.coff..min(self.buffer.coff + self.buffer.w, line.len())];
-                self.bt
-                    .add_change(&Vec::from(part).iter().collect::<String>());
-                self.bt.add_change("\r\n");
-            } else {
-                self.bt.add_change("~\r\n");
-            }
-        }
+        self.draw_status_bar();
+        self.draw_message_bar();
 
         self.bt.add_changes(vec![
             Change::CursorPosition {
-                x: Position::Absolute(self.buffer.cx),
-                y: Position::Absolute(self.buffer.cy),
+                x: Position::Absolute(cx),
+                y: Position::Absolute(cy),
             },
             Change::CursorShape(CursorShape::Default),
         ]);
     }
 
-    fn handle_keys(&mut self) -> Result<()> {
-        match self.bt.terminal().poll_input(None) {
-            Ok(Some(input)) => match input {
-                InputEvent::Key(KeyEvent {
-                    key: KeyCode::Char('Q'),
-                    modifiers: Modifiers::CTRL,
-                }) => {
-                    self.should_quit = true;
+    /// Renders the buffer as text lines within the scrolled viewport; returns
+    /// the on-screen cursor position.
+    fn draw_text(&mut self) -> (usize, usize) {
+        let buffer = &self.buffers[self.active];
+        let rows = buffer.roff..(buffer.roff + buffer.h.saturating_sub(2));
+        for i in rows {
+            if i < self.buffers[self.active].num_lines() {
+                let rendered = self.buffers[self.active].render_line(i);
+                let highlight = self.search_match.filter(|(mcy, ..)| *mcy == i).map(
+                    |(mcy, mcx, mlen)| {
+                        (
+                            self.buffers[self.active].render_x(mcy, mcx),
+                            self.buffers[self.active].render_x(mcy, mcx + mlen),
+                        )
+                    },
+                );
+                self.draw_line(&rendered, highlight);
+            } else {
+                self.bt.add_change("~\r\n");
+            }
+        }
+
+        let buffer = &self.buffers[self.active];
+        (
+            buffer.render_x(buffer.cy, buffer.cx).saturating_sub(buffer.coff),
+            buffer.cy - buffer.roff,
+        )
+    }
+
+    /// Renders the buffer as a hex dump (offset, 16 bytes per row in hex,
+    /// ASCII gutter) within the scrolled viewport; returns the on-screen
+    /// cursor position.
+    fn draw_hex(&mut self) -> (usize, usize) {
+        let view_height = self.buffers[self.active].h.saturating_sub(2);
+        let roff = self.buffers[self.active].hex_roff;
+        let len = self.buffers[self.active].hex_bytes.len();
+
+        for row in roff..roff + view_height {
+            let start = row * BYTES_PER_LINE;
+            if start >= len && row != len / BYTES_PER_LINE {
+                self.bt.add_change("~\r\n");
+                continue;
+            }
+            let end = min(start + BYTES_PER_LINE, len);
+
+            let mut line = format!("{:08x}  ", start);
+            for i in 0..BYTES_PER_LINE {
+                if start + i < end {
+                    let byte = self.buffers[self.active].hex_bytes[start + i];
+                    line.push_str(&format!("{:02x} ", byte));
+                } else {
+                    line.push_str("   ");
                 }
-                InputEvent::Key(KeyEvent {
-                    key: KeyCode::Tab, ..
-                }) => {
-                    for _ in 0..4 {
-                        self.buffer.push(' ');
+                if i == 7 {
+                    line.push(' ');
+                }
+            }
+            line.push(' ');
+            for &b in &self.buffers[self.active].hex_bytes[start..end] {
+                let c = b as char;
+                line.push(if c.is_ascii_graphic() || c == ' ' { c } else { '.' });
+            }
+            self.draw_line(&line, None);
+        }
+
+        let buffer = &self.buffers[self.active];
+        let row = buffer.hex_cursor / BYTES_PER_LINE;
+        let col = buffer.hex_cursor % BYTES_PER_LINE;
+        let group_gap = if col >= 8 { 1 } else { 0 };
+        let nibble = if buffer.hex_high_nibble { 0 } else { 1 };
+        let x = 10 + col * 3 + group_gap + nibble;
+        (x.saturating_sub(buffer.coff), row - buffer.hex_roff)
+    }
+
+    /// Renders one already-tab-expanded line, clipped to the horizontal scroll
+    /// window and with the `[start, end)` render-column range (if any) reverse-video.
+    fn draw_line(&mut self, rendered: &str, highlight: Option<(usize, usize)>) {
+        let coff = self.buffers[self.active].coff;
+        let w = self.buffers[self.active].w;
+        let mut col = 0usize;
+        let mut segment = String::new();
+        let mut reversed = false;
+
+        for c in rendered.chars() {
+            let cw = c.width().unwrap_or(0);
+            if col >= coff + w {
+                break;
+            }
+            if col >= coff {
+                let want_reverse = highlight.is_some_and(|(s, e)| col >= s && col < e);
+                if want_reverse != reversed {
+                    if !segment.is_empty() {
+                        self.bt.add_change(std::mem::take(&mut segment));
                     }
+                    self.bt
+                        .add_change(Change::Attribute(AttributeChange::Reverse(want_reverse)));
+                    reversed = want_reverse;
                 }
+                segment.push(c);
+            }
+            col += cw;
+        }
+        if !segment.is_empty() {
+            self.bt.add_change(segment);
+        }
+        if reversed {
+            self.bt
+                .add_change(Change::Attribute(AttributeChange::Reverse(false)));
+        }
+        self.bt.add_change("\r\n");
+    }
+
+    /// Finds the nearest occurrence of `query` from `(cy, cx)`, wrapping around the file.
+    fn find_match(&self, query: &str, from: (usize, usize), forward: bool) -> Option<(usize, usize)> {
+        let needle: Vec<char> = query.chars().collect();
+        if needle.is_empty() {
+            return None;
+        }
+        let n = self.buffers[self.active].num_lines();
+        let (start_cy, start_cx) = from;
+
+        for i in 0..n {
+            let cy = if forward {
+                (start_cy + i) % n
+            } else {
+                (start_cy + n - i) % n
+            };
+            let line = self.buffers[self.active].line_chars(cy);
+            let line = line.as_slice();
+
+            if forward {
+                let search_from = if i == 0 { start_cx.min(line.len()) } else { 0 };
+                if let Some(pos) = find_subslice(&line[search_from..], &needle) {
+                    return Some((cy, search_from + pos));
+                }
+            } else {
+                let search_to = if i == 0 { start_cx.min(line.len()) } else { line.len() };
+                if let Some(pos) = rfind_subslice(&line[..search_to], &needle) {
+                    return Some((cy, pos));
+                }
+            }
+        }
+
+        // The loop above never revisits the anchor line's own prefix
+        // (forward) or suffix (backward) relative to the cursor, so a full
+        // wrap can still miss a match that only occurs there; check it last.
+        let line = self.buffers[self.active].line_chars(start_cy);
+        let line = line.as_slice();
+        let cx = start_cx.min(line.len());
+        if forward {
+            find_subslice(&line[..cx], &needle).map(|pos| (start_cy, pos))
+        } else {
+            rfind_subslice(&line[cx..], &needle).map(|pos| (start_cy, cx + pos))
+        }
+    }
+
+    /// Incremental search triggered by Ctrl-F: live-updates the match as the
+    /// user types, supports stepping to the next/previous match, and restores
+    /// the original cursor and scroll position on Escape.
+    fn search(&mut self, rx: &mpsc::Receiver<TermEvent>) -> Result<()> {
+        let saved = (
+            self.buffers[self.active].cy,
+            self.buffers[self.active].cx,
+            self.buffers[self.active].roff,
+            self.buffers[self.active].coff,
+        );
+        let mut query = String::new();
+        let mut anchor = (saved.0, saved.1);
+        let mut forward = true;
+
+        loop {
+            self.search_match = if query.is_empty() {
+                None
+            } else if let Some((cy, cx)) = self.find_match(&query, anchor, forward) {
+                self.buffers[self.active].cy = cy;
+                self.buffers[self.active].cx = cx;
+                self.buffers[self.active].move_caret(0, 0);
+                Some((cy, cx, query.chars().count()))
+            } else {
+                None
+            };
+
+            self.set_status_message(format!("Search: {}", query));
+            self.draw_screen();
+            self.bt.flush()?;
+
+            match self.recv_input(rx)? {
+                InputEvent::Resized { cols, rows } => self.handle_resize(cols, rows),
                 InputEvent::Key(KeyEvent {
-                    key: KeyCode::Char(c),
+                    key: KeyCode::Escape,
                     ..
                 }) => {
-                    self.buffer.push(c);
+                    (
+                        self.buffers[self.active].cy,
+                        self.buffers[self.active].cx,
+                        self.buffers[self.active].roff,
+                        self.buffers[self.active].coff,
+                    ) = saved;
+                    self.search_match = None;
+                    return Ok(());
                 }
                 InputEvent::Key(KeyEvent {
                     key: KeyCode::Enter,
                     ..
                 }) => {
-                    self.buffer.push('\n');
+                    self.search_match = None;
+                    return Ok(());
                 }
                 InputEvent::Key(KeyEvent {
-                    key: KeyCode::LeftArrow,
-                    ..
-                }) => self.buffer.move_caret(0, -1),
-                InputEvent::Key(KeyEvent {
-                    key: KeyCode::RightArrow,
+                    key: KeyCode::DownArrow | KeyCode::RightArrow,
                     ..
-                }) => self.buffer.move_caret(0, 1),
-                InputEvent::Key(KeyEvent {
-                    key: KeyCode::UpArrow,
-                    ..
-                }) => self.buffer.move_caret(-1, 0),
+                }) => {
+                    if let Some((cy, cx, len)) = self.search_match {
+                        anchor = (cy, cx + len);
+                        forward = true;
+                    }
+                }
                 InputEvent::Key(KeyEvent {
-                    key: KeyCode::DownArrow,
+                    key: KeyCode::UpArrow | KeyCode::LeftArrow,
                     ..
-                }) => self.buffer.move_caret(1, 0),
+                }) => {
+                    if let Some((cy, cx, _)) = self.search_match {
+                        anchor = (cy, cx);
+                        forward = false;
+                    }
+                }
                 InputEvent::Key(KeyEvent {
                     key: KeyCode::Backspace,
                     ..
-                }) => self.buffer.backspace(),
+                }) => {
+                    query.pop();
+                    anchor = (saved.0, saved.1);
+                    forward = true;
+                }
                 InputEvent::Key(KeyEvent {
-                    key: KeyCode::Delete,
+                    key: KeyCode::Char(c),
                     ..
-                }) => self.buffer.delete(),
+                }) => {
+                    query.push(c);
+                    anchor = (saved.0, saved.1);
+                    forward = true;
+                }
                 _ => {}
-            },
-            Ok(None) => {}
-            Err(e) => {
-                println!("{:?}\r\n", e);
-                self.should_quit = true;
             }
         }
+    }
+
+    /// Renders the reverse-video row showing filename, line count and cursor position.
+    fn draw_status_bar(&mut self) {
+        let filename = self.buffers[self.active]
+            .path
+            .as_ref()
+            .and_then(|p| p.file_name())
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "[No Name]".to_string());
+        let modified = if self.buffers[self.active].dirty { " [modified]" } else { "" };
+        let buf_count = if self.buffers.len() > 1 {
+            format!(" ({}/{})", self.active + 1, self.buffers.len())
+        } else {
+            String::new()
+        };
+
+        let (left, right) = match self.buffers[self.active].mode {
+            Mode::Text => (
+                format!(
+                    "{}{}{} - {} lines",
+                    filename,
+                    modified,
+                    buf_count,
+                    self.buffers[self.active].num_lines()
+                ),
+                format!(
+                    "{}:{}",
+                    self.buffers[self.active].cy + 1,
+                    self.buffers[self.active].cx + 1
+                ),
+            ),
+            Mode::Hex => (
+                format!(
+                    "{}{}{} - {} bytes [hex]",
+                    filename,
+                    modified,
+                    buf_count,
+                    self.buffers[self.active].hex_bytes.len()
+                ),
+                format!("{:#010x}", self.buffers[self.active].hex_cursor),
+            ),
+        };
+
+        let mut status = left;
+        let width = self.buffers[self.active].w;
+        if status.len() + right.len() < width {
+            status.push_str(&" ".repeat(width - status.len() - right.len()));
+            status.push_str(&right);
+        }
+        status.truncate(width);
+        while status.len() < width {
+            status.push(' ');
+        }
+
+        self.bt.add_changes(vec![
+            Change::Attribute(AttributeChange::Reverse(true)),
+            Change::Text(status),
+            Change::Attribute(AttributeChange::Reverse(false)),
+            Change::Text("\r\n".to_string()),
+        ]);
+    }
+
+    /// Renders the transient status message, clearing it once it times out.
+    fn draw_message_bar(&mut self) {
+        if let Some((_, set_at)) = &self.status_message {
+            if set_at.elapsed() > STATUS_MESSAGE_TIMEOUT {
+                self.status_message = None;
+            }
+        }
+
+        self.bt
+            .add_change(Change::ClearToEndOfLine(ColorAttribute::Default));
+        if let Some((msg, _)) = &self.status_message {
+            let mut msg = msg.clone();
+            msg.truncate(self.buffers[self.active].w);
+            self.bt.add_change(msg);
+        }
+    }
+
+    /// Updates every buffer's dimensions after a terminal resize and
+    /// reclamps each buffer's scroll so its cursor stays on-screen, not just
+    /// the active one — every buffer shares the same terminal dimensions, and
+    /// an inactive buffer must already have a valid scroll position for when
+    /// it's switched to.
+    fn handle_resize(&mut self, cols: usize, rows: usize) {
+        for buffer in &mut self.buffers {
+            buffer.w = cols;
+            buffer.h = rows;
+            buffer.move_caret(0, 0);
+            buffer.move_hex_caret(0);
+        }
+    }
+
+    fn handle_input(&mut self, input: InputEvent, rx: &mpsc::Receiver<TermEvent>) -> Result<()> {
+        if let InputEvent::Resized { cols, rows } = input {
+            self.handle_resize(cols, rows);
+            return Ok(());
+        }
+
+        let is_quit = matches!(
+            input,
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Char('Q'),
+                modifiers: Modifiers::CTRL,
+            })
+        );
+        if !is_quit {
+            self.quit_times = QUIT_TIMES;
+        }
+
+        match input {
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Char('Q'),
+                modifiers: Modifiers::CTRL,
+            }) => {
+                let dirty_names: Vec<&str> = self
+                    .buffers
+                    .iter()
+                    .filter(|b| b.dirty)
+                    .map(|b| {
+                        b.path
+                            .as_ref()
+                            .and_then(|p| p.file_name())
+                            .and_then(|n| n.to_str())
+                            .unwrap_or("[No Name]")
+                    })
+                    .collect();
+                if !dirty_names.is_empty() && self.quit_times > 1 {
+                    self.quit_times -= 1;
+                    self.set_status_message(format!(
+                        "Unsaved changes in {}! Press Ctrl-Q {} more times to quit without saving",
+                        dirty_names.join(", "),
+                        self.quit_times
+                    ));
+                } else {
+                    self.should_quit = true;
+                }
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Char('S'),
+                modifiers: Modifiers::CTRL,
+            }) => {
+                self.save(rx)?;
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Char('Z'),
+                modifiers: Modifiers::CTRL,
+            }) => {
+                if self.buffers[self.active].mode == Mode::Text {
+                    self.buffers[self.active].undo();
+                }
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Char('Y'),
+                modifiers: Modifiers::CTRL,
+            }) => {
+                if self.buffers[self.active].mode == Mode::Text {
+                    self.buffers[self.active].redo();
+                }
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Char('F'),
+                modifiers: Modifiers::CTRL,
+            }) => {
+                if self.buffers[self.active].mode == Mode::Text {
+                    self.search(rx)?;
+                }
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Char('T'),
+                modifiers: Modifiers::CTRL,
+            }) => {
+                self.toggle_mode();
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Char('N'),
+                modifiers: Modifiers::CTRL,
+            }) => {
+                self.next_buffer();
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Char('P'),
+                modifiers: Modifiers::CTRL,
+            }) => {
+                self.prev_buffer();
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Char('B'),
+                modifiers: Modifiers::CTRL,
+            }) => {
+                self.pick_buffer(rx)?;
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Tab, ..
+            }) => {
+                if self.buffers[self.active].mode == Mode::Text {
+                    self.buffers[self.active].push('\t');
+                }
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Char(c),
+                ..
+            }) if self.buffers[self.active].mode == Mode::Text => {
+                self.buffers[self.active].push(c);
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Char(c),
+                ..
+            }) => {
+                if let Some(digit) = c.to_digit(16) {
+                    self.buffers[self.active].hex_write_nibble(digit as u8);
+                }
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Enter,
+                ..
+            }) => {
+                if self.buffers[self.active].mode == Mode::Text {
+                    self.buffers[self.active].push('\n');
+                }
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::LeftArrow,
+                ..
+            }) if self.buffers[self.active].mode == Mode::Text => {
+                self.buffers[self.active].flush_group();
+                self.buffers[self.active].move_caret(0, -1);
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::LeftArrow,
+                ..
+            }) => self.buffers[self.active].move_hex_caret(-1),
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::RightArrow,
+                ..
+            }) if self.buffers[self.active].mode == Mode::Text => {
+                self.buffers[self.active].flush_group();
+                self.buffers[self.active].move_caret(0, 1);
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::RightArrow,
+                ..
+            }) => self.buffers[self.active].move_hex_caret(1),
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::UpArrow,
+                ..
+            }) if self.buffers[self.active].mode == Mode::Text => {
+                self.buffers[self.active].flush_group();
+                self.buffers[self.active].move_caret(-1, 0);
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::UpArrow,
+                ..
+            }) => self.buffers[self.active].move_hex_caret(-(BYTES_PER_LINE as i32)),
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::DownArrow,
+                ..
+            }) if self.buffers[self.active].mode == Mode::Text => {
+                self.buffers[self.active].flush_group();
+                self.buffers[self.active].move_caret(1, 0);
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::DownArrow,
+                ..
+            }) => self.buffers[self.active].move_hex_caret(BYTES_PER_LINE as i32),
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Backspace,
+                ..
+            }) if self.buffers[self.active].mode == Mode::Text => {
+                self.buffers[self.active].backspace();
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Backspace,
+                ..
+            }) => {
+                if self.buffers[self.active].hex_cursor > 0 {
+                    self.buffers[self.active].move_hex_caret(-1);
+                    self.buffers[self.active].hex_delete();
+                }
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Delete,
+                ..
+            }) if self.buffers[self.active].mode == Mode::Text => {
+                self.buffers[self.active].delete();
+            }
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Delete,
+                ..
+            }) => self.buffers[self.active].hex_delete(),
+            InputEvent::Key(KeyEvent {
+                key: KeyCode::Insert,
+                ..
+            }) => {
+                if self.buffers[self.active].mode == Mode::Hex {
+                    self.buffers[self.active].hex_insert();
+                }
+            }
+            _ => {}
+        }
         Ok(())
     }
 }
 
 fn main() -> Result<()> {
     let mut editor = Editor::new()?;
-    let mut args: Vec<String> = std::env::args().collect();
-    if args.len() == 2 {
-        editor.open(PathBuf::from(args.remove(1)))?;
-    } else {
-        println!("Error: too many arguments");
-        println!("usage wilo [FILE]");
-        return Ok(());
+    for path in std::env::args().skip(1) {
+        editor.open(PathBuf::from(path))?;
     }
     editor.run()?;
     Ok(())